@@ -3,6 +3,16 @@
 // 2. There can only be ONE owner at a time.
 // 3. When the owner goes out of scope, the value will be dropped.
 
+use std::borrow::Cow;
+use std::cell::{Cell, OnceCell, RefCell};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+
 fn mutable_string() {
     println!("Mutable string...");
     // let s = "hello"; can't be mutated.
@@ -95,6 +105,7 @@ fn function_with_return() {
     println!("Getting back s and store in s2: {s2}");
 }
 
+#[allow(clippy::ptr_arg)]
 fn calculate_length(s: &String) -> usize {
     s.len()
 }
@@ -170,6 +181,7 @@ fn rules_of_mutable_references() {
 // }
 
 // This function is not dangle as returning the complex data like this won't drop s. It will transfer its ownership to any variables receive this function result
+#[allow(clippy::let_and_return)]
 fn no_dangle() -> String {
     let s = String::from("hello");
     s
@@ -200,6 +212,7 @@ fn using_slice() {
     println!("Slice 4: {slice4}");
 }
 
+#[allow(clippy::redundant_slicing)]
 fn first_word(s: &str) -> &str {
     let bytes = s.as_bytes();
 
@@ -228,6 +241,7 @@ fn using_slice_function() {
     println!("First word is {first}");
 }
 
+#[allow(clippy::redundant_slicing)]
 fn general_string_slice() {
     println!("General string slice...");
 
@@ -258,6 +272,2622 @@ fn other_slices() {
 
 }
 
+fn tuple_partial_move() {
+    println!("Tuple partial move...");
+
+    let pair = (String::from("hello"), 5);
+
+    // Destructuring moves the String out of the tuple, but the i32 is just copied
+    let (s, n) = pair;
+
+    // pair.0 has been moved, so the tuple as a whole is no longer valid
+    // println!("{:?}", pair); // This will cause an error
+    // pair.1 is still valid because i32 is Copy and only a copy was taken
+    println!("s = {s}, n = {n}, pair.1 = {}", pair.1);
+
+    assert_eq!(s, "hello");
+    assert_eq!(n, 5);
+    assert_eq!(pair.1, 5);
+}
+
+// The output lifetime 'a is tied to both inputs.
+// The returned reference is only valid as long as BOTH a and b are still valid,
+// because the compiler cannot know which one will be returned.
+fn pick_longer<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if a.len() >= b.len() {
+        a
+    } else {
+        b
+    }
+}
+
+fn returning_borrowed_data() {
+    println!("Returning borrowed data...");
+
+    let s1 = String::from("hello world");
+    let s2 = String::from("hi");
+
+    // result borrows from s1 (or s2), it doesn't own anything
+    let result = pick_longer(&s1, &s2);
+
+    // Dropping s1 here will cause an error as result is still used below
+    // drop(s1);
+
+    println!("The longer one is: {result}");
+
+    assert_eq!(result, "hello world");
+    // result points into the same buffer as s1, no copy was made
+    assert_eq!(result.as_ptr(), s1.as_ptr());
+}
+
+fn shared_mutable_state() {
+    println!("Shared mutable state...");
+
+    // Rc gives us multiple owners, RefCell lets us mutate through a shared handle.
+    // The borrow rules are still enforced, but at runtime instead of compile time.
+    // This is the single-threaded equivalent of Arc<Mutex<T>>
+    let shared = Rc::new(RefCell::new(Vec::new()));
+
+    // Cloning an Rc only copies the pointer and increases the reference count
+    let handle1 = Rc::clone(&shared);
+    let handle2 = Rc::clone(&shared);
+
+    // Each borrow_mut() is dropped at the end of its statement,
+    // so there is never more than one mutable borrow at a time
+    handle1.borrow_mut().push(String::from("from handle 1"));
+    handle2.borrow_mut().push(String::from("from handle 2"));
+
+    println!("Shared vector: {:?}", shared.borrow());
+    println!("Reference count: {}", Rc::strong_count(&shared));
+
+    assert_eq!(*shared.borrow(), vec!["from handle 1", "from handle 2"]);
+}
+
+fn count_with_threads(n: usize) -> i32 {
+    let counter = Arc::new(Mutex::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..n {
+        // Each thread gets its own Arc, which is moved into the closure
+        let counter = Arc::clone(&counter);
+        let handle = thread::spawn(move || {
+            // lock() gives us a MutexGuard which acts like a &mut i32
+            // Only one thread can hold it at a time, so there is only ONE mutable reference
+            let mut num = counter.lock().unwrap();
+            *num += 1;
+            // The lock is released when num goes out of scope
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = *counter.lock().unwrap();
+    total
+}
+
+fn shared_mutable_threads() {
+    println!("Shared mutable threads...");
+
+    // A plain &mut i32 can't be given to multiple threads as it breaks the "one mutable reference" rule.
+    // Rc<RefCell<T>> isn't thread-safe either, so we use Arc (atomic Rc) and Mutex (a lock) instead.
+    let total = count_with_threads(10);
+    println!("Total count: {total}");
+
+    assert_eq!(total, 10);
+}
+
+struct Celsius(f64);
+
+struct Fahrenheit(f64);
+
+// Implementing From also gives us Into for free
+impl From<Celsius> for Fahrenheit {
+    fn from(c: Celsius) -> Self {
+        Fahrenheit(c.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+fn from_into_demo() {
+    println!("From and Into...");
+
+    // From takes its argument by value, so c1 is moved into the conversion
+    let c1 = Celsius(100.0);
+    let f1 = Fahrenheit::from(c1);
+    // println!("{}", c1.0); // This will cause an error as c1 is moved
+
+    // into() is the same conversion written from the other side, c2 is moved as well
+    let c2 = Celsius(0.0);
+    let f2: Fahrenheit = c2.into();
+    // println!("{}", c2.0); // This will cause an error as c2 is moved
+
+    println!("100C = {}F, 0C = {}F", f1.0, f2.0);
+
+    assert_eq!(f1.0, 212.0);
+    assert_eq!(f2.0, 32.0);
+}
+
+fn chunks_and_windows() {
+    println!("Chunks and windows...");
+    let a = [1, 2, 3, 4, 5];
+    let slice: &[i32] = &a;
+
+    // chunks(2) splits the slice into non-overlapping sub-slices
+    // The last chunk can be shorter: [1, 2], [3, 4], [5]
+    let chunks: Vec<&[i32]> = slice.chunks(2).collect();
+
+    // windows(2) gives overlapping sub-slices: [1, 2], [2, 3], [3, 4], [4, 5]
+    let windows: Vec<&[i32]> = slice.windows(2).collect();
+
+    // Both only borrow from a, nothing is copied
+    println!("Chunks: {:?}", chunks);
+    println!("Windows: {:?}", windows);
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0], &[1, 2]);
+    assert_eq!(windows.len(), 4);
+    assert_eq!(windows[0], &[1, 2]);
+    // The first chunk points to the same memory as a
+    assert_eq!(chunks[0].as_ptr(), a.as_ptr());
+}
+
+fn map_remove_ownership() {
+    println!("Map remove ownership...");
+
+    let mut map = HashMap::new();
+    // Both the key and the value are moved into the map
+    map.insert(String::from("color"), String::from("blue"));
+
+    // get() only borrows the value, the map still owns it
+    let borrowed: Option<&String> = map.get("color");
+    println!("Borrowed value: {:?}", borrowed);
+
+    // remove() moves the value out of the map, we now own it
+    let removed: Option<String> = map.remove("color");
+    println!("Removed value: {:?}", removed);
+
+    assert_eq!(removed, Some(String::from("blue")));
+    // The map no longer has the value
+    assert_eq!(map.get("color"), None);
+}
+
+// f only borrows the string it's given, so s stays owned by the caller
+fn higher_order(f: impl Fn(&str) -> usize, s: &str) -> usize {
+    f(s)
+}
+
+fn higher_order_demo() {
+    println!("Higher order function...");
+
+    let s = String::from("hello world");
+
+    // The closure receives a borrowed &str and returns an owned usize
+    let len = higher_order(|x| first_word(x).len(), &s);
+
+    // s is still valid as it was only borrowed by the callback
+    println!("First word length of '{s}' is {len}");
+
+    assert_eq!(len, 5);
+}
+
+fn flatten_ownership() {
+    println!("Flatten ownership...");
+
+    let nested = vec![
+        vec![String::from("a"), String::from("b")],
+        vec![String::from("c")],
+    ];
+
+    // into_iter() consumes the outer vec, flatten() consumes each inner vec.
+    // Every String is moved (not copied) into the new flat vector
+    let flat: Vec<String> = nested.into_iter().flatten().collect();
+
+    // nested is no longer valid as it was consumed
+    // println!("{:?}", nested); // This will cause an error
+    println!("Flat: {:?}", flat);
+
+    assert_eq!(flat, vec!["a", "b", "c"]);
+    assert_eq!(flat.len(), 3);
+}
+
+fn trim_demos() {
+    println!("Trim demos...");
+
+    let s = String::from("  hello  ");
+
+    // trim() returns a &str pointing into s, nothing is allocated or changed
+    let trimmed: &str = s.trim();
+
+    // to_string() creates a new owned String from the borrowed slice
+    let owned: String = s.trim().to_string();
+
+    // Trimming s itself in place would require a &mut String,
+    // e.g. a function like fn trim_in_place(s: &mut String)
+
+    println!("Original: '{s}', trimmed: '{trimmed}', owned: '{owned}'");
+
+    assert_eq!(trimmed, "hello");
+    assert_eq!(owned, "hello");
+    // The original string is unchanged
+    assert_eq!(s, "  hello  ");
+}
+
+// Both map and map_err take self, so the original Result is consumed
+fn transform_result(r: Result<String, String>) -> Result<usize, String> {
+    r.map(|s| s.len()).map_err(|e| e.to_uppercase())
+}
+
+fn result_ownership() {
+    println!("Result ownership...");
+
+    let ok: Result<String, String> = Ok(String::from("hello"));
+    let err: Result<String, String> = Err(String::from("not found"));
+
+    let ok_len = transform_result(ok);
+    let err_upper = transform_result(err);
+
+    // ok and err are no longer valid as they were moved into transform_result
+    // println!("{:?}", ok); // This will cause an error
+    println!("Ok: {:?}, Err: {:?}", ok_len, err_upper);
+
+    assert_eq!(ok_len, Ok(5));
+    assert_eq!(err_upper, Err(String::from("NOT FOUND")));
+}
+
+// Like first_word but for every word. Each item is a &str borrowing from s
+fn words(s: &str) -> impl Iterator<Item = &str> {
+    s.split_whitespace()
+}
+
+fn pipeline(s: &str) -> Vec<String> {
+    // filter() only looks at the borrowed words.
+    // map() turns each borrowed &str into a new owned String.
+    // collect() gathers the owned Strings into a Vec that we return to the caller
+    words(s)
+        .filter(|w| w.len() > 2)
+        .map(|w| w.to_uppercase())
+        .collect()
+}
+
+fn iterator_pipeline() {
+    println!("Iterator pipeline...");
+
+    let s = String::from("a to the");
+    let result = pipeline(&s);
+
+    // s is still valid, the result owns its own data
+    println!("Pipeline of '{s}': {:?}", result);
+
+    assert_eq!(pipeline("a to the"), vec!["THE"]);
+}
+
+// calculate_length only accepts &String. AsRef<str> accepts anything that can be borrowed as a &str,
+// e.g. String, &String, &str, Box<str>, ... It generalizes the &String / &str split
+fn as_ref_demo<T: AsRef<str>>(x: T) -> usize {
+    x.as_ref().len()
+}
+
+// AsMut works the same way but hands out a mutable borrow
+fn as_mut_demo<T: AsMut<String>>(mut x: T) {
+    x.as_mut().push('!');
+}
+
+fn as_ref_and_as_mut() {
+    println!("AsRef and AsMut...");
+
+    let owned = String::from("hello");
+    // Passing &owned only borrows, passing owned would move it into the function
+    let len1 = as_ref_demo(&owned);
+    let len2 = as_ref_demo("hi");
+    let len3 = as_ref_demo(owned);
+
+    // String itself only implements AsMut<str>, but Box<String> implements AsMut<String>.
+    // Passing &mut boxed lets us mutate without giving up ownership
+    let mut s = Box::new(String::from("hello"));
+    as_mut_demo(&mut s);
+
+    println!("Lengths: {len1} {len2} {len3}, mutated: {s}");
+
+    assert_eq!(len1, 5);
+    assert_eq!(len2, 2);
+    assert_eq!(len3, 5);
+    assert_eq!(*s, "hello!");
+}
+
+// The returned Vec holds references into source, so it is bound by the same lifetime 'a.
+// If source is dropped, every &str in the Vec would dangle. That's why the Vec can't outlive source
+// The lifetime could be elided here, but it is written out to show the relationship
+#[allow(clippy::needless_lifetimes)]
+fn ref_vec_lifetime<'a>(source: &'a str) -> Vec<&'a str> {
+    words(source).collect()
+}
+
+fn ref_vec_demo() {
+    println!("Vector of references...");
+
+    let source = String::from("hello big world");
+    let parts = ref_vec_lifetime(&source);
+
+    // Dropping source here will cause an error as parts is still used below
+    // drop(source);
+
+    println!("Parts: {:?}", parts);
+
+    assert_eq!(parts, vec!["hello", "big", "world"]);
+    // Each part still points into the original buffer
+    assert_eq!(parts[0].as_ptr(), source.as_ptr());
+    assert_eq!(parts[1].as_ptr(), source[6..].as_ptr());
+}
+
+fn to_owned_slices() {
+    println!("ToOwned on slices...");
+
+    let a = [1, 2, 3];
+    let slice: &[i32] = &a;
+    let text: &str = "hello";
+
+    // to_owned() is the generic counterpart of clone() for borrowed data.
+    // Cloning a &[i32] would just give another &[i32], to_owned() gives an owned Vec<i32> instead
+    let mut owned_vec: Vec<i32> = slice.to_owned();
+    let owned_string: String = text.to_owned();
+
+    // ToOwned is the trait behind Cow: Cow<'a, B> borrows a B or owns a B::Owned
+    // e.g. Cow<str> holds either a &str or a String
+
+    // The owned copy is independent from the original
+    owned_vec.push(4);
+
+    println!("Slice: {:?}, owned: {:?}, string: {owned_string}", slice, owned_vec);
+
+    assert_eq!(&owned_vec[..3], slice);
+    assert_eq!(owned_vec, vec![1, 2, 3, 4]);
+    assert_eq!(slice, &[1, 2, 3]);
+    assert_eq!(owned_string, text);
+}
+
+// A recursive type needs a Box, otherwise its size would be infinite.
+// Box stores the next node on the heap and owns it
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+// Borrowing the list lets us traverse it without taking ownership
+fn sum_list(list: &List) -> i32 {
+    match list {
+        List::Cons(value, next) => value + sum_list(next),
+        List::Nil => 0,
+    }
+}
+
+fn linked_list_build() {
+    println!("Linked list build...");
+
+    // Each tail is moved into the next node, so the outermost node owns the whole chain
+    let tail = List::Cons(3, Box::new(List::Nil));
+    let middle = List::Cons(2, Box::new(tail));
+    let list = List::Cons(1, Box::new(middle));
+
+    // tail and middle are no longer valid as they were moved into list
+    // sum_list(&tail); // This will cause an error
+
+    let sum = sum_list(&list);
+    // list is still valid as sum_list only borrowed it
+    let sum_again = sum_list(&list);
+    println!("Sum of list: {sum}");
+
+    assert_eq!(sum, 6);
+    assert_eq!(sum_again, 6);
+}
+
+// Parser borrows its input for 'a. It can't outlive the string it reads from
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser { input, pos: 0 }
+    }
+
+    // The returned word has lifetime 'a (the input), not the lifetime of &mut self.
+    // So the word stays valid even after we call next_word again
+    fn next_word(&mut self) -> Option<&'a str> {
+        let rest = &self.input[self.pos..];
+        let trimmed = rest.trim_start_matches(' ');
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        let start = self.pos + (rest.len() - trimmed.len());
+        // Same idea as first_word, but starting from the current position
+        let word = first_word(trimmed);
+        self.pos = start + word.len();
+
+        Some(word)
+    }
+}
+
+fn parser_demo() {
+    println!("Parser demo...");
+
+    let input = String::from("a b c");
+    let mut parser = Parser::new(&input);
+
+    let first = parser.next_word();
+    let second = parser.next_word();
+    let third = parser.next_word();
+
+    // first is still usable here even though parser was mutably borrowed again
+    println!("Words: {:?} {:?} {:?}", first, second, third);
+
+    assert_eq!(first, Some("a"));
+    assert_eq!(second, Some("b"));
+    assert_eq!(third, Some("c"));
+    assert_eq!(parser.next_word(), None);
+}
+
+#[derive(Default, Debug, PartialEq)]
+struct Settings {
+    name: String,
+    retries: i32,
+}
+
+fn default_demo() {
+    println!("Default demo...");
+
+    // Each default() creates a fresh owned value
+    let s = String::default();
+    let v = Vec::<i32>::default();
+    let settings = Settings::default();
+
+    println!("String: '{s}', Vec: {:?}, Settings: {:?}", v, settings);
+
+    // std::mem::take moves the old value out and leaves a default in its place.
+    // This lets us take ownership of a field through a &mut without leaving it invalid
+    let mut current = Settings {
+        name: String::from("server"),
+        retries: 3,
+    };
+    let old_name = std::mem::take(&mut current.name);
+    println!("Took '{old_name}', left '{}'", current.name);
+
+    assert_eq!(s, "");
+    assert!(v.is_empty());
+    assert_eq!(settings, Settings { name: String::new(), retries: 0 });
+    assert_eq!(old_name, "server");
+    assert_eq!(current.name, "");
+    assert_eq!(current.retries, 3);
+}
+
+// Matching on a &[i32] binds first as &i32 and rest as &[i32], both borrowing from items.
+// We copy the head out (i32 is Copy) and hand back the tail as a sub-slice
+fn slice_patterns(items: &[i32]) -> Option<(i32, &[i32])> {
+    if let [first, rest @ ..] = items {
+        Some((*first, rest))
+    } else {
+        None
+    }
+}
+
+fn slice_pattern_demo() {
+    println!("Slice patterns...");
+
+    let a = [1, 2, 3];
+    let split = slice_patterns(&a);
+    let empty = slice_patterns(&[]);
+
+    println!("Split: {:?}, empty: {:?}", split, empty);
+
+    assert_eq!(split, Some((1, &a[1..])));
+    assert_eq!(empty, None);
+}
+
+// from_utf8 takes the Vec by value. On success the same heap buffer becomes the String, no copy needed
+fn bytes_to_string(bytes: Vec<u8>) -> Result<String, std::string::FromUtf8Error> {
+    String::from_utf8(bytes)
+}
+
+fn bytes_to_string_demo() {
+    println!("Bytes to string...");
+
+    let valid = bytes_to_string(vec![104, 105]);
+    let invalid = bytes_to_string(vec![0xff, 0xfe]);
+
+    // from_utf8_lossy only borrows the bytes and returns a Cow<str>.
+    // It is borrowed if the bytes were already valid, or owned if invalid bytes had to be replaced
+    let raw = [104, 0xff, 105];
+    let lossy = String::from_utf8_lossy(&raw);
+
+    println!("Valid: {:?}, invalid is error: {}, lossy: {lossy}", valid, invalid.is_err());
+
+    assert_eq!(valid.unwrap(), "hi");
+    // The error gives the original Vec back, so ownership isn't lost
+    assert_eq!(invalid.unwrap_err().into_bytes(), vec![0xff, 0xfe]);
+    assert_eq!(lossy, "h\u{FFFD}i");
+}
+
+fn scoped_threads() {
+    println!("Scoped threads...");
+
+    let words = vec![
+        String::from("one"),
+        String::from("three"),
+        String::from("five"),
+        String::from("seven"),
+    ];
+
+    // thread::spawn requires 'static data as the thread may outlive the current function.
+    // thread::scope guarantees every thread is joined before the scope ends,
+    // so the threads can simply borrow words without Arc or moving it
+    let total: usize = thread::scope(|s| {
+        let handles: Vec<_> = words
+            .chunks(2)
+            .map(|chunk| s.spawn(move || chunk.iter().map(|w| w.len()).sum::<usize>()))
+            .collect();
+
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+
+    // words is still owned here, the threads only borrowed it
+    println!("Total length of {:?}: {total}", words);
+
+    assert_eq!(total, 17);
+}
+
+fn array_vs_vec_copy() {
+    println!("Array vs Vec copy...");
+
+    // An array of Copy types is Copy itself as it lives entirely on the stack
+    let a1 = [1, 2, 3];
+    let a2 = a1;
+    // Both arrays are valid
+    println!("a1 = {:?}, a2 = {:?}", a1, a2);
+
+    // A Vec stores its data on the heap, so assignment moves the pointer like String
+    let v1 = vec![1, 2, 3];
+    let v2 = v1;
+    // println!("{:?}", v1); // This will cause an error as v1 is moved
+    println!("v2 = {:?}", v2);
+
+    assert_eq!(a1, [1, 2, 3]);
+    assert_eq!(a1, a2);
+    assert_eq!(v2, vec![1, 2, 3]);
+}
+
+fn scoped_borrows() {
+    println!("Scoped borrows...");
+
+    let mut s = String::from("hello");
+
+    {
+        // Two immutable borrows at the same time are fine
+        let r1 = &s;
+        let r2 = &s;
+        println!("r1: {r1}, r2: {r2}");
+    } // r1 and r2 go out of scope here
+
+    {
+        // No other borrow is alive anymore, so we can take a mutable one
+        let r3 = &mut s;
+        r3.push_str(", world");
+    }
+
+    println!("Final: {s}");
+
+    assert_eq!(s, "hello, world");
+}
+
+// as_str() just reborrows the String's buffer as a &str, nothing is copied
+#[allow(clippy::ptr_arg)]
+fn explicit_reborrow(s: &String) -> &str {
+    s.as_str()
+}
+
+fn reborrow_str_demo() {
+    println!("Reborrow String as str...");
+
+    let s = String::from("hello");
+
+    // Explicit conversion
+    let explicit = explicit_reborrow(&s);
+    // Implicit conversion: &String is coerced into &str (deref coercion), same result
+    let implicit: &str = &s;
+
+    println!("Explicit: {explicit}, implicit: {implicit}");
+
+    assert_eq!(explicit, "hello");
+    assert_eq!(explicit.as_ptr(), s.as_ptr());
+    assert_eq!(implicit.as_ptr(), s.as_ptr());
+}
+
+// The accumulator owns the growing String. Each word is moved into the closure,
+// its contents are appended and then the word is dropped
+fn fold_ownership(words: Vec<String>) -> String {
+    words.into_iter().fold(String::new(), |mut acc, w| {
+        acc.push_str(&w);
+        acc
+    })
+}
+
+fn fold_demo() {
+    println!("Fold ownership...");
+
+    let words = vec![String::from("hello"), String::from(" "), String::from("world")];
+    let joined = fold_ownership(words);
+
+    // words is no longer valid as it was consumed by fold_ownership
+    // println!("{:?}", words); // This will cause an error
+    println!("Folded: {joined}");
+
+    assert_eq!(joined, "hello world");
+    assert_eq!(fold_ownership(vec![]), "");
+}
+
+fn borrow_trait_lookup() {
+    println!("Borrow trait lookup...");
+
+    let mut scores: HashMap<String, i32> = HashMap::new();
+    scores.insert(String::from("alice"), 10);
+
+    // HashMap::get accepts any &Q where String: Borrow<Q>.
+    // String implements Borrow<str>, so we can look up with a plain &str
+    // instead of allocating a new String for every lookup.
+    // Borrow also promises the borrowed form hashes and compares the same as the owned form
+    let score = scores.get("alice");
+
+    println!("alice: {:?}", score);
+
+    assert_eq!(score, Some(&10));
+    assert_eq!(scores.get("bob"), None);
+}
+
+// Moves i back to the start of the character it lands in (or to s.len() if it's past the end).
+// Same as str::floor_char_boundary, which is only available since Rust 1.91
+fn floor_boundary(s: &str, i: usize) -> usize {
+    let mut i = i.min(s.len());
+    while !s.is_char_boundary(i) {
+        i -= 1;
+    }
+    i
+}
+
+// Slicing a &str in the middle of a multi-byte character panics,
+// so we snap the index back to a char boundary first
+fn safe_byte_slice(s: &str, approx: usize) -> &str {
+    let end = floor_boundary(s, approx);
+    &s[..end]
+}
+
+fn safe_byte_slice_demo() {
+    println!("Safe byte slice...");
+
+    // "é" takes 2 bytes, so index 2 is in the middle of it
+    let s = "héllo";
+    // &s[..2] would panic here
+    println!("First 2 bytes (snapped): '{}'", safe_byte_slice(s, 2));
+
+    // Try every index, none of them panic
+    for i in 0..=s.len() {
+        let slice = safe_byte_slice(s, i);
+        assert!(s.starts_with(slice));
+    }
+    assert_eq!(safe_byte_slice(s, 2), "h");
+    assert_eq!(safe_byte_slice(s, 3), "hé");
+}
+
+// s is moved in and dropped when this function returns.
+// An iterator of &str borrowing from s would dangle, so the iterator has to own its words
+fn owned_word_iter(s: String) -> impl Iterator<Item = String> {
+    let owned: Vec<String> = words(&s).map(String::from).collect();
+    owned.into_iter()
+}
+
+fn owned_word_iter_demo() {
+    println!("Owned word iterator...");
+
+    let s = String::from("hello big world");
+    let iter = owned_word_iter(s);
+
+    // s is no longer valid, but iter doesn't need it
+    // println!("{s}"); // This will cause an error
+    let collected: Vec<String> = iter.collect();
+    println!("Owned words: {:?}", collected);
+
+    assert_eq!(collected, vec!["hello", "big", "world"]);
+}
+
+// Cell never hands out a reference to its inner value, it only copies values in and out.
+// Since nobody can hold a reference into it, mutating through a shared &Cell is safe.
+// That's why Cell is meant for Copy types, RefCell is used when we need to borrow non-Copy data
+fn bump(counter: &Cell<i32>) {
+    counter.set(counter.get() + 1);
+}
+
+fn cell_demo() {
+    println!("Cell demo...");
+
+    let counter = Cell::new(0);
+    let shared = &counter;
+
+    bump(shared);
+    bump(shared);
+    shared.set(shared.get() * 10);
+
+    println!("Counter: {}", counter.get());
+
+    assert_eq!(counter.get(), 20);
+}
+
+type Slot = Rc<RefCell<Option<Box<dyn Fn(u64) -> u64>>>>;
+
+fn recursive_closure() {
+    println!("Recursive closure...");
+
+    // A closure can't refer to itself by name.
+    // Instead, we create a shared slot first and let the closure call whatever is stored in it
+    let slot: Slot = Rc::new(RefCell::new(None));
+
+    // The closure only holds a Weak reference to the slot.
+    // Holding an Rc would create a cycle (slot owns closure, closure owns slot) and leak memory
+    let weak = Rc::downgrade(&slot);
+    let fact = move |n: u64| -> u64 {
+        if n <= 1 {
+            return 1;
+        }
+        let slot = weak.upgrade().unwrap();
+        let inner = slot.borrow();
+        n * (inner.as_ref().unwrap())(n - 1)
+    };
+
+    // Move the closure into the slot, the slot owns it from now on
+    *slot.borrow_mut() = Some(Box::new(fact));
+
+    let result = (slot.borrow().as_ref().unwrap())(5);
+    println!("5! = {result}");
+
+    assert_eq!(result, 120);
+}
+
+// into_iter() takes ownership of v, next() moves the first element out to the caller.
+// The iterator (and every remaining element) is dropped when the function returns
+fn take_first_owned(v: Vec<String>) -> Option<String> {
+    v.into_iter().next()
+}
+
+fn take_first_owned_demo() {
+    println!("Take first owned...");
+
+    let v = vec![String::from("first"), String::from("second")];
+
+    // v[0].clone() would allocate a copy and keep v valid.
+    // let first = v[0]; // This will cause an error as we can't move out of an index
+    let first = take_first_owned(v);
+    println!("First: {:?}", first);
+
+    assert_eq!(first, Some(String::from("first")));
+    assert_eq!(take_first_owned(vec![]), None);
+}
+
+// Hash::hash takes &self, so hashing a key only borrows it
+#[derive(Hash, PartialEq, Eq, Debug)]
+struct Id(String);
+
+fn custom_hash_key() {
+    println!("Custom hash key...");
+
+    let mut users = HashMap::new();
+    let id = Id(String::from("u1"));
+
+    // The key is moved into the map on insert
+    users.insert(id, String::from("Alice"));
+    // println!("{:?}", id); // This will cause an error as id is moved
+
+    // Looking up only needs a borrowed key
+    let lookup = Id(String::from("u1"));
+    let name = users.get(&lookup);
+    println!("{:?} => {:?}", lookup, name);
+
+    assert_eq!(name, Some(&String::from("Alice")));
+}
+
+struct Registry {
+    names: Vec<String>,
+}
+
+impl Registry {
+    // The returned reference borrows from self (the lifetime is elided to &self).
+    // The registry can't be mutated or dropped while the reference is in use
+    fn get(&self, i: usize) -> Option<&String> {
+        self.names.get(i)
+    }
+
+    // Borrows self to read the names but returns a brand new String,
+    // so the summary stays valid even after the registry is gone
+    fn summary(&self) -> String {
+        format!("{} names: {}", self.names.len(), self.names.join(", "))
+    }
+}
+
+fn registry_demo() {
+    println!("Registry demo...");
+
+    let registry = Registry {
+        names: vec![String::from("alice"), String::from("bob")],
+    };
+
+    let first = registry.get(0);
+    let missing = registry.get(5);
+    println!("First: {:?}, missing: {:?}", first, missing);
+
+    assert_eq!(first, Some(&String::from("alice")));
+    assert_eq!(registry.get(1), Some(&String::from("bob")));
+    assert_eq!(missing, None);
+}
+
+fn once_cell_demo() {
+    println!("OnceCell demo...");
+
+    let cell: OnceCell<String> = OnceCell::new();
+    let init_count = Cell::new(0);
+
+    let init = || {
+        init_count.set(init_count.get() + 1);
+        String::from("config loaded")
+    };
+
+    // The first call runs the closure and moves the String into the cell.
+    // Every later call just returns a &String borrowing the stored value
+    let first = cell.get_or_init(init);
+    let second = cell.get_or_init(init);
+    let third = cell.get_or_init(init);
+
+    println!("Value: {first}, initialized {} time(s)", init_count.get());
+
+    assert_eq!(first, "config loaded");
+    assert_eq!(second, third);
+    assert_eq!(init_count.get(), 1);
+}
+
+fn box_str_demo() {
+    println!("Box<str> demo...");
+
+    let mut s = String::with_capacity(32);
+    s.push_str("hello");
+
+    // A String is { ptr, len, capacity }. A Box<str> is only { ptr, len } and can't grow.
+    // into_boxed_str() moves the data into the box and shrinks the allocation to fit,
+    // which saves memory for strings that will never change
+    let boxed: Box<str> = s.into_boxed_str();
+    // s is no longer valid as it was moved into boxed
+    // println!("{s}"); // This will cause an error
+
+    // into_string() moves it back into a growable String without copying
+    let back: String = boxed.into_string();
+    println!("Round trip: {back}, capacity: {}", back.capacity());
+
+    assert_eq!(back, "hello");
+    assert_eq!(back.capacity(), 5);
+}
+
+fn split_off_demo() {
+    println!("Split off demo...");
+
+    let mut v = vec![String::from("a"), String::from("b"), String::from("c"), String::from("d")];
+
+    // split_off(2) moves the elements from index 2 onward into a new Vec.
+    // v keeps ownership of the head, tail owns the suffix
+    let tail = v.split_off(2);
+
+    println!("Head: {:?}, tail: {:?}", v, tail);
+
+    assert_eq!(v, vec!["a", "b"]);
+    assert_eq!(tail, vec!["c", "d"]);
+}
+
+struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+// Deref lets *my_box give us the inner value, and lets &MyBox<T> be coerced into &T
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+fn custom_deref() {
+    println!("Custom deref...");
+
+    let my_box = MyBox::new(String::from("hello world"));
+
+    // &MyBox<String> -> &String (our Deref) -> &str (String's Deref)
+    // The compiler inserts these deref calls for us (deref coercion)
+    let first = first_word(&my_box);
+
+    // *my_box is the same as *(my_box.deref())
+    let len = (*my_box).len();
+    println!("First word: {first}, length: {len}");
+
+    assert_eq!(first, "hello");
+    assert_eq!(len, 11);
+}
+
+// Matching on &Option<String> binds s as &String, so nothing is moved out of the option.
+// The guard only reads through that borrow
+fn describe_option(opt: &Option<String>) -> &'static str {
+    match opt {
+        Some(s) if s.len() > 3 => "long",
+        Some(_) => "short",
+        None => "none",
+    }
+}
+
+fn match_guard_ownership() {
+    println!("Match guard ownership...");
+
+    let long = Some(String::from("hello"));
+    let short = Some(String::from("hi"));
+    let none: Option<String> = None;
+
+    println!("{:?} is {}", long, describe_option(&long));
+
+    assert_eq!(describe_option(&long), "long");
+    assert_eq!(describe_option(&short), "short");
+    assert_eq!(describe_option(&none), "none");
+    // The options are still usable after matching
+    assert_eq!(long, Some(String::from("hello")));
+}
+
+fn sort_ownership() {
+    println!("Sort ownership...");
+
+    // sort() borrows the vec as &mut and reorders it in place.
+    // Use this when we no longer need the original order
+    let mut v = vec![3, 1, 2];
+    v.sort();
+
+    // To keep the original, clone first and sort the copy.
+    // This costs an extra allocation, so only do this when both orders are needed
+    let original = vec![3, 1, 2];
+    let mut sorted = original.clone();
+    sorted.sort();
+
+    println!("In place: {:?}, original: {:?}, sorted copy: {:?}", v, original, sorted);
+
+    assert_eq!(v, vec![1, 2, 3]);
+    assert_eq!(sorted, vec![1, 2, 3]);
+    assert_eq!(original, vec![3, 1, 2]);
+}
+
+fn dedup_demo() {
+    println!("Dedup demo...");
+
+    let mut v = vec![
+        String::from("pear"),
+        String::from("apple"),
+        String::from("pear"),
+        String::from("apple"),
+        String::from("fig"),
+    ];
+
+    // dedup() only removes ADJACENT duplicates, so we sort first to group them together
+    v.sort();
+    // The removed Strings are dropped right here and their heap memory is freed.
+    // The remaining ones stay owned by v
+    v.dedup();
+
+    println!("Deduplicated: {:?}", v);
+
+    assert_eq!(v, vec!["apple", "fig", "pear"]);
+}
+
+// An async task is a state machine that owns everything it needs between polls.
+// Task owns its steps and only lends them out one at a time
+struct Task {
+    steps: Vec<String>,
+    cursor: usize,
+}
+
+impl Task {
+    fn new(steps: Vec<String>) -> Self {
+        Task { steps, cursor: 0 }
+    }
+
+    // Returns a borrow of the current step and advances the cursor.
+    // The &str can't be held across the next poll as both need self
+    fn poll(&mut self) -> Option<&str> {
+        let step = self.steps.get(self.cursor)?;
+        self.cursor += 1;
+        Some(step.as_str())
+    }
+}
+
+fn task_demo() {
+    println!("Task demo...");
+
+    let mut task = Task::new(vec![
+        String::from("connect"),
+        String::from("send"),
+        String::from("close"),
+    ]);
+
+    let mut completed = Vec::new();
+    while let Some(step) = task.poll() {
+        println!("Step: {step}");
+        // Copy the borrowed step into an owned String so we can keep it after the next poll
+        completed.push(step.to_string());
+    }
+
+    assert_eq!(completed, vec!["connect", "send", "close"]);
+    assert_eq!(task.poll(), None);
+}
+
+// A hand-rolled version of Cow<str>: either borrows a &str or owns a String
+enum MaybeOwned<'a> {
+    Borrowed(&'a str),
+    Owned(String),
+}
+
+impl MaybeOwned<'_> {
+    // Either way the caller gets a &str, borrowed from self
+    fn as_str(&self) -> &str {
+        match self {
+            MaybeOwned::Borrowed(s) => s,
+            MaybeOwned::Owned(s) => s.as_str(),
+        }
+    }
+}
+
+// Only allocate when we actually need to change the input
+fn shout_if_quiet(input: &str) -> MaybeOwned<'_> {
+    if input.ends_with('!') {
+        MaybeOwned::Borrowed(input)
+    } else {
+        MaybeOwned::Owned(format!("{input}!"))
+    }
+}
+
+fn maybe_owned_demo() {
+    println!("MaybeOwned demo...");
+
+    let loud = shout_if_quiet("hello!");
+    let quiet = shout_if_quiet("hello");
+
+    println!("Loud: {}, quiet: {}", loud.as_str(), quiet.as_str());
+
+    assert!(matches!(loud, MaybeOwned::Borrowed(_)));
+    assert!(matches!(quiet, MaybeOwned::Owned(_)));
+    assert_eq!(loud.as_str(), "hello!");
+    assert_eq!(quiet.as_str(), "hello!");
+}
+
+fn into_iterator_refs() {
+    println!("IntoIterator on references...");
+
+    // Vec<T> has three IntoIterator impls:
+    // 1. for &Vec<T>     => yields &T     (for x in &v)
+    // 2. for &mut Vec<T> => yields &mut T (for x in &mut v)
+    // 3. for Vec<T>      => yields T      (for x in v), consumes the vec
+    let mut v = vec![1, 2, 3];
+
+    // Same as (&v).into_iter(), v is only borrowed
+    let mut sum = 0;
+    for x in &v {
+        sum += x;
+    }
+
+    // Same as (&mut v).into_iter(), each element is borrowed mutably
+    for x in &mut v {
+        *x *= 10;
+    }
+
+    // v is still usable after both loops
+    println!("Sum: {sum}, v: {:?}", v);
+
+    // Same as v.into_iter(), v is moved into the loop
+    let mut total = 0;
+    for x in v {
+        total += x;
+    }
+    // println!("{:?}", v); // This will cause an error as v is moved
+
+    assert_eq!(sum, 6);
+    assert_eq!(total, 60);
+}
+
+fn retain_mut_demo() {
+    println!("Retain mut demo...");
+
+    let mut v = vec![
+        String::from("hi"),
+        String::from("extremely"),
+        String::from("ok"),
+    ];
+
+    // The closure gets an exclusive &mut String for each element in turn,
+    // so it can change the element and then decide whether to keep it.
+    // Elements that return false are dropped
+    v.retain_mut(|s| {
+        s.push('!');
+        s.len() < 10
+    });
+
+    println!("Retained: {:?}", v);
+
+    assert_eq!(v, vec!["hi!", "ok!"]);
+}
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+// mem::swap only moves the two values between the locations, it never clones.
+// That's why it works for any T, even types that aren't Copy or Clone
+fn generic_swap<T>(a: &mut T, b: &mut T) {
+    std::mem::swap(a, b);
+}
+
+fn generic_swap_demo() {
+    println!("Generic swap...");
+
+    let mut s1 = String::from("left");
+    let mut s2 = String::from("right");
+    generic_swap(&mut s1, &mut s2);
+
+    let mut p1 = Point { x: 1, y: 2 };
+    let mut p2 = Point { x: 3, y: 4 };
+    generic_swap(&mut p1, &mut p2);
+
+    println!("s1 = {s1}, s2 = {s2}, p1 = {:?}, p2 = {:?}", p1, p2);
+
+    assert_eq!(s1, "right");
+    assert_eq!(s2, "left");
+    assert_eq!(p1, Point { x: 3, y: 4 });
+    assert_eq!(p2, Point { x: 1, y: 2 });
+}
+
+// Strings are UTF-8, so one char can take 1 to 4 bytes.
+// first_word scans bytes because a space is always a single byte
+// and byte indexes are what we need to slice the string.
+// s.bytes().count() is the same as s.len(), it is written this way to mirror s.chars().count()
+#[allow(clippy::bytes_count_to_len)]
+fn byte_vs_char_scan(s: &str) -> (usize, usize) {
+    (s.bytes().count(), s.chars().count())
+}
+
+fn byte_vs_char_demo() {
+    println!("Bytes vs chars...");
+
+    let ascii = byte_vs_char_scan("hello");
+    let multi_byte = byte_vs_char_scan("héllo");
+
+    println!("ascii (bytes, chars): {:?}, multi-byte: {:?}", ascii, multi_byte);
+
+    assert_eq!(ascii, (5, 5));
+    assert_eq!(multi_byte, (6, 5));
+}
+
+struct Pool {
+    handles: Vec<thread::JoinHandle<String>>,
+}
+
+impl Pool {
+    fn new() -> Self {
+        Pool { handles: Vec::new() }
+    }
+
+    // The task closure is moved into the new thread, which owns it from now on.
+    // 'static means the closure can't borrow anything local, it must own its data
+    fn submit(&mut self, task: impl FnOnce() -> String + Send + 'static) {
+        self.handles.push(thread::spawn(task));
+    }
+
+    // Consumes the pool, so no more tasks can be submitted after joining
+    fn join_all(self) -> Vec<String> {
+        self.handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .collect()
+    }
+}
+
+fn pool_demo() {
+    println!("Pool demo...");
+
+    let mut pool = Pool::new();
+    for i in 0..3 {
+        let name = format!("task {i}");
+        // move transfers ownership of name into the closure, then into the thread
+        pool.submit(move || format!("{name} done"));
+    }
+
+    let results = pool.join_all();
+    // pool is no longer valid as join_all consumed it
+    println!("Results: {:?}", results);
+
+    assert_eq!(results, vec!["task 0 done", "task 1 done", "task 2 done"]);
+}
+
+fn rc_get_mut() {
+    println!("Rc get_mut...");
+
+    // With only one owner, nobody else can observe the change, so mutation is allowed
+    let mut unique = Rc::new(String::from("hello"));
+    if let Some(s) = Rc::get_mut(&mut unique) {
+        s.push_str(", world");
+    }
+    println!("Unique: {unique}");
+
+    // With more than one owner, handing out a &mut would break the borrow rules
+    let mut shared = Rc::new(String::from("hello"));
+    let other = Rc::clone(&shared);
+    let result = Rc::get_mut(&mut shared);
+    println!("Shared get_mut is none: {}, count: {}", result.is_none(), Rc::strong_count(&other));
+
+    assert_eq!(*unique, "hello, world");
+    assert!(Rc::get_mut(&mut shared).is_none());
+    assert_eq!(*other, "hello");
+}
+
+// i32 is Copy, so every variant just copies the numbers out and never moves from a
+// The index loop is kept on purpose to compare it with the iterator versions
+#[allow(clippy::needless_range_loop)]
+fn array_sum_variants(a: &[i32; 5]) -> i32 {
+    // 1. Index loop: works, but we have to manage the index and bounds ourselves
+    let mut by_index = 0;
+    for i in 0..a.len() {
+        by_index += a[i];
+    }
+
+    // 2. Slice iterator: no index, each item is a &i32
+    let mut by_iter = 0;
+    for item in &a[..] {
+        by_iter += item;
+    }
+
+    // 3. sum(): the shortest and most idiomatic
+    let by_sum: i32 = a.iter().sum();
+
+    assert_eq!(by_index, by_iter);
+    assert_eq!(by_iter, by_sum);
+    by_sum
+}
+
+fn array_sum_demo() {
+    println!("Array sum variants...");
+
+    let a = [1, 2, 3, 4, 5];
+    let total = array_sum_variants(&a);
+    println!("Total: {total}");
+
+    assert_eq!(total, 15);
+}
+
+fn once_lock_demo() {
+    println!("OnceLock demo...");
+
+    // OnceLock is the thread-safe version of OnceCell.
+    // The String is moved into the static once, then every thread only borrows it
+    static GREETING: OnceLock<String> = OnceLock::new();
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            thread::spawn(|| {
+                // Only one thread runs the initializer, the others wait and get the same value.
+                // The returned &String is 'static as the static lives for the whole program
+                let greeting: &'static String = GREETING.get_or_init(|| String::from("hello"));
+                greeting.as_ptr() as usize
+            })
+        })
+        .collect();
+
+    let addresses: Vec<usize> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    println!("Greeting: {}", GREETING.get().unwrap());
+
+    assert_eq!(GREETING.get().map(|s| s.as_str()), Some("hello"));
+    // Every thread saw the very same String, not a copy
+    assert!(addresses.iter().all(|&a| a == addresses[0]));
+}
+
+struct Record {
+    key: String,
+    hits: u32,
+}
+
+fn vec_of_structs_borrow() {
+    println!("Vec of structs borrow...");
+
+    let mut v: Vec<Record> = [("home", 0), ("about", 7)]
+        .into_iter()
+        .map(|(key, hits)| Record { key: String::from(key), hits })
+        .collect();
+
+    // Indexing borrows the WHOLE vec, the compiler can't tell that v[0] and v[1] are different elements
+    // let hits = &mut v[0].hits;
+    // let key = &v[1].key; // This will cause an error as v is already borrowed mutably
+    // *hits += 1;
+
+    // split_at_mut gives two non-overlapping mutable slices, so both borrows can live at the same time
+    let (first, rest) = v.split_at_mut(1);
+    let hits = &mut first[0].hits;
+    let key = &rest[0].key;
+    *hits += 1;
+    println!("Bumped {} while reading {key}", first[0].key);
+
+    // Borrowing two fields of the SAME element is fine, the compiler tracks fields separately
+    let record = &mut v[1];
+    let key = &record.key;
+    record.hits += 1;
+    println!("{key} now has {} hits", record.hits);
+
+    assert_eq!(v[0].hits, 1);
+    assert_eq!(v[1].hits, 8);
+    assert_eq!(v[1].key, "about");
+}
+
+fn extend_demo() {
+    println!("Extend demo...");
+
+    let mut v = vec![String::from("a")];
+
+    // Extending from owned Strings moves each one into v
+    let owned = vec![String::from("b"), String::from("c")];
+    v.extend(owned);
+    // owned is no longer valid as it was consumed by extend
+    // println!("{:?}", owned); // This will cause an error
+
+    // From borrowed &str we have to create owned Strings first
+    let borrowed: &[&str] = &["d", "e"];
+    v.extend(borrowed.iter().map(|s| s.to_string()));
+
+    println!("Extended: {:?}, borrowed still valid: {:?}", v, borrowed);
+
+    assert_eq!(v, vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(v.len(), 5);
+}
+
+// Works like first_word: scan until the condition fails and return a slice of s up to there
+fn run_of(s: &str, c: char) -> &str {
+    for (i, item) in s.char_indices() {
+        if item != c {
+            return &s[..i];
+        }
+    }
+
+    s
+}
+
+fn run_of_demo() {
+    println!("Run of char...");
+
+    let s = String::from("aaab");
+    let run = run_of(&s, 'a');
+    println!("Leading run of 'a' in '{s}': '{run}'");
+
+    assert_eq!(run_of("aaab", 'a'), "aaa");
+    assert_eq!(run_of("baaa", 'a'), "");
+    assert_eq!(run_of("aaa", 'a'), "aaa");
+    assert_eq!(run_of("ééx", 'é'), "éé");
+}
+
+fn option_map_ownership() {
+    println!("Option map ownership...");
+
+    // map() takes self, so the option (and the String inside) is consumed
+    let opt = Some(String::from("hello"));
+    let len = opt.map(|s| s.len());
+    // println!("{:?}", opt); // This will cause an error as opt is moved
+
+    // as_mut() turns &mut Option<String> into Option<&mut String>,
+    // so map() only gets a mutable borrow and the option stays usable
+    let mut kept = Some(String::from("hello"));
+    let new_len = kept.as_mut().map(|s| {
+        s.push('!');
+        s.len()
+    });
+
+    println!("Length: {:?}, new length: {:?}, kept: {:?}", len, new_len, kept);
+
+    assert_eq!(len, Some(5));
+    assert_eq!(new_len, Some(6));
+    assert_eq!(kept, Some(String::from("hello!")));
+}
+
+fn mutate_str_slice() {
+    println!("Mutate str slice...");
+
+    let mut s = String::from("hello world");
+
+    // &mut str is a mutable borrow of part of the String's buffer.
+    // It can't change its length, so only changes that keep every byte count the same are allowed.
+    // ASCII upper/lowercase is exactly that: one byte in, one byte out, no reallocation
+    let word: &mut str = &mut s[..5];
+    word.make_ascii_uppercase();
+
+    println!("Mutated: {s}");
+
+    assert_eq!(s, "HELLO world");
+}
+
+#[derive(Debug, PartialEq)]
+struct Percent(u8);
+
+// Unlike From, TryFrom can fail, so it returns a Result.
+// The input is still consumed either way (i32 is Copy, so here it's just copied)
+impl TryFrom<i32> for Percent {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if (0..=100).contains(&value) {
+            Ok(Percent(value as u8))
+        } else {
+            Err(format!("{value} is not between 0 and 100"))
+        }
+    }
+}
+
+fn try_from_demo() {
+    println!("TryFrom demo...");
+
+    let valid = Percent::try_from(42);
+    let too_large: Result<Percent, _> = 150.try_into();
+    let negative = Percent::try_from(-1);
+
+    println!("Valid: {:?}, too large: {:?}, negative: {:?}", valid, too_large, negative);
+
+    assert_eq!(valid, Ok(Percent(42)));
+    assert!(too_large.is_err());
+    assert!(negative.is_err());
+}
+
+// into_iter() of a Vec is double-ended, so rev() can move the elements out from the back
+fn reverse_consume(v: Vec<String>) -> Vec<String> {
+    v.into_iter().rev().collect()
+}
+
+fn reverse_consume_demo() {
+    println!("Reverse consume...");
+
+    let v = vec![String::from("a"), String::from("b"), String::from("c")];
+    let reversed = reverse_consume(v);
+
+    // v is no longer valid as its elements were moved into reversed
+    // println!("{:?}", v); // This will cause an error
+    println!("Reversed: {:?}", reversed);
+
+    assert_eq!(reversed, vec!["c", "b", "a"]);
+}
+
+// Loud records its own drop in a shared log so we can see exactly when it happens
+struct Loud {
+    name: String,
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl Drop for Loud {
+    fn drop(&mut self) {
+        println!("Dropping {}", self.name);
+        self.log.borrow_mut().push(format!("drop {}", self.name));
+    }
+}
+
+thread_local! {
+    // The shared log for drop_on_early_return, so the function doesn't need a log parameter
+    static EARLY_RETURN_LOG: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(Vec::new()));
+}
+
+fn drop_on_early_return(fail: bool) {
+    let log = EARLY_RETURN_LOG.with(Rc::clone);
+    let _guard = Loud {
+        name: String::from("guard"),
+        log: Rc::clone(&log),
+    };
+
+    if fail {
+        log.borrow_mut().push(String::from("early return"));
+        // _guard goes out of scope here, so it is dropped before returning
+        return;
+    }
+
+    log.borrow_mut().push(String::from("completed"));
+    // ...and here on the normal path
+}
+
+// Runs drop_on_early_return and takes everything it wrote to the log
+fn run_and_take_log(fail: bool) -> Vec<String> {
+    drop_on_early_return(fail);
+    EARLY_RETURN_LOG.with(|log| std::mem::take(&mut *log.borrow_mut()))
+}
+
+fn drop_on_early_return_demo() {
+    println!("Drop on early return...");
+
+    let early_log = run_and_take_log(true);
+    let normal_log = run_and_take_log(false);
+
+    assert_eq!(early_log, vec!["early return", "drop guard"]);
+    assert_eq!(normal_log, vec!["completed", "drop guard"]);
+}
+
+// Every part is a &str pointing into s, no new Strings are allocated
+fn split_demos(s: &str) -> (Vec<&str>, Vec<&str>) {
+    let forward = s.split(',').collect();
+    // rsplit walks from the end, so the parts come out in reverse order
+    let backward = s.rsplit(',').collect();
+    (forward, backward)
+}
+
+fn split_demo() {
+    println!("Split demos...");
+
+    let s = String::from("a,b,c");
+    let (forward, backward) = split_demos(&s);
+
+    println!("Forward: {:?}, backward: {:?}", forward, backward);
+
+    assert_eq!(forward, vec!["a", "b", "c"]);
+    assert_eq!(backward, vec!["c", "b", "a"]);
+}
+
+struct SentenceBuilder {
+    parts: Vec<String>,
+}
+
+impl SentenceBuilder {
+    fn new() -> Self {
+        SentenceBuilder { parts: Vec::new() }
+    }
+
+    // Consuming style: takes self by value and gives it back.
+    // Chaining is easy, but the builder must be reassigned if we add words in a loop
+    fn add(mut self, word: String) -> Self {
+        self.parts.push(word);
+        self
+    }
+
+    // Mutable-borrow style: the caller keeps ownership of the builder.
+    // Works well in loops and conditionals, but the chain can't be moved out directly
+    fn add_owned(&mut self, word: String) -> &mut Self {
+        self.parts.push(word);
+        self
+    }
+
+    // Lends out the words added so far without consuming the builder.
+    // The slice borrows from self, so it can't be used after build() moves the builder
+    fn parts(&self) -> &[String] {
+        &self.parts
+    }
+
+    fn build(self) -> String {
+        self.parts.join(" ")
+    }
+}
+
+fn sentence_builder_demo() {
+    println!("Sentence builder...");
+
+    let consumed = SentenceBuilder::new()
+        .add(String::from("hello"))
+        .add(String::from("world"))
+        .build();
+
+    let mut builder = SentenceBuilder::new();
+    builder.add_owned(String::from("ownership")).add_owned(String::from("is"));
+    for word in ["fun", "!"] {
+        builder.add_owned(String::from(word));
+    }
+    let borrowed = builder.build();
+
+    println!("Consumed: {consumed}, borrowed: {borrowed}");
+
+    assert_eq!(consumed, "hello world");
+    assert_eq!(borrowed, "ownership is fun !");
+}
+
+fn compare_demo() {
+    println!("Compare demo...");
+
+    let a = String::from("apple");
+    let b = String::from("banana");
+
+    // Ord::cmp takes &self and &other, so both Strings are only borrowed
+    let ordering = a.cmp(&b);
+
+    // Cloning before comparing works too, but allocates two Strings for nothing
+    let cloned_ordering = a.clone().cmp(&b.clone());
+
+    // a and b are still valid
+    println!("{a} vs {b}: {:?}", ordering);
+
+    assert_eq!(ordering, Ordering::Less);
+    assert_eq!(cloned_ordering, ordering);
+    assert_eq!(a.cmp(&String::from("apple")), Ordering::Equal);
+    assert_eq!(b.cmp(&a), Ordering::Greater);
+}
+
+fn fnonce_demo() {
+    println!("FnOnce demo...");
+
+    let s = String::from("hello");
+
+    // move puts s into the closure. Returning s moves it back out of the closure's environment,
+    // so the closure only implements FnOnce: after one call there is nothing left to return
+    let give_back = move || s;
+
+    let result = give_back();
+    // let again = give_back(); // This will cause an error as give_back was already consumed
+    println!("Got back: {result}");
+
+    assert_eq!(result, "hello");
+}
+
+// as_ref() turns &Option<T> into Option<&T> (and &Result<T, E> into Result<&T, &E>)
+// so we can look inside without moving anything out of x
+fn nested_borrow(x: &Option<Result<String, String>>) -> Option<&str> {
+    x.as_ref()?.as_ref().ok().map(|s| s.as_str())
+}
+
+fn nested_borrow_demo() {
+    println!("Nested borrow...");
+
+    let ok = Some(Ok(String::from("value")));
+    let err = Some(Err(String::from("oops")));
+    let none = None;
+
+    println!("Ok: {:?}, Err: {:?}, None: {:?}", nested_borrow(&ok), nested_borrow(&err), nested_borrow(&none));
+
+    assert_eq!(nested_borrow(&ok), Some("value"));
+    assert_eq!(nested_borrow(&err), None);
+    assert_eq!(nested_borrow(&none), None);
+    // ok is still intact
+    assert_eq!(ok, Some(Ok(String::from("value"))));
+}
+
+fn forget_demo() {
+    println!("Forget demo...");
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    {
+        // A normal value is dropped at the end of its scope
+        let _normal = Loud {
+            name: String::from("normal"),
+            log: Rc::clone(&log),
+        };
+    }
+
+    let forgotten = Loud {
+        name: String::from("forgotten"),
+        log: Rc::clone(&log),
+    };
+    // WARNING: mem::forget takes ownership and then never runs Drop.
+    // The String inside (and the Rc clone) are LEAKED, their memory is never freed.
+    // It's not unsafe, but it is almost never what we want.
+    // Prefer letting values go out of scope, or use ManuallyDrop when we really need control
+    std::mem::forget(forgotten);
+
+    println!("Log: {:?}", log.borrow());
+
+    assert_eq!(*log.borrow(), vec!["drop normal"]);
+    // The leaked Rc clone is still counted, as it was never dropped
+    assert_eq!(Rc::strong_count(&log), 2);
+}
+
+// Like split_demos, but any of the delimiters ends a part. The parts still borrow from s
+fn split_any<'a>(s: &'a str, delims: &[char]) -> Vec<&'a str> {
+    s.split(|c| delims.contains(&c)).collect()
+}
+
+fn split_any_demo() {
+    println!("Split any...");
+
+    let s = String::from("a,b;c");
+    let parts = split_any(&s, &[',', ';']);
+    println!("Parts: {:?}", parts);
+
+    assert_eq!(parts, vec!["a", "b", "c"]);
+    // Two delimiters in a row give an empty part between them
+    assert_eq!(split_any("a,;b", &[',', ';']), vec!["a", "", "b"]);
+}
+
+fn binary_heap_demo() {
+    println!("Binary heap demo...");
+
+    let mut heap = BinaryHeap::new();
+
+    // push() moves each String into the heap
+    heap.push(String::from("banana"));
+    heap.push(String::from("cherry"));
+    heap.push(String::from("apple"));
+
+    // peek() only borrows the largest value
+    println!("Largest: {:?}", heap.peek());
+
+    // pop() moves the largest value out to us
+    let mut popped = Vec::new();
+    while let Some(s) = heap.pop() {
+        popped.push(s);
+    }
+    println!("Popped: {:?}", popped);
+
+    assert_eq!(popped, vec!["cherry", "banana", "apple"]);
+    assert!(heap.is_empty());
+}
+
+fn map_iteration() {
+    println!("Map iteration...");
+
+    let mut scores: HashMap<String, i32> = HashMap::new();
+    scores.insert(String::from("alice"), 1);
+    scores.insert(String::from("bob"), 2);
+
+    // iter() yields (&String, &i32), the map keeps ownership of everything
+    let mut borrowed_sum = 0;
+    for (k, v) in scores.iter() {
+        println!("{k}: {v}");
+        borrowed_sum += v;
+    }
+
+    // values_mut() yields &mut i32, keys can't be changed as that would break the hashing
+    for v in scores.values_mut() {
+        *v *= 10;
+    }
+
+    // into_iter() yields (String, i32) and consumes the map
+    let mut owned_sum = 0;
+    let mut names = Vec::new();
+    for (k, v) in scores {
+        names.push(k);
+        owned_sum += v;
+    }
+    // println!("{:?}", scores); // This will cause an error as scores is moved
+    names.sort();
+    println!("Borrowed sum: {borrowed_sum}, owned sum: {owned_sum}, names: {:?}", names);
+
+    assert_eq!(borrowed_sum, 3);
+    assert_eq!(owned_sum, 30);
+    assert_eq!(names, vec!["alice", "bob"]);
+}
+
+// collect() can build a Result<Vec<_>, _> from an iterator of Results.
+// It stops at the first Err and returns it, otherwise we get Ok with every value
+fn collect_result(inputs: &[&str]) -> Result<Vec<i32>, std::num::ParseIntError> {
+    inputs.iter().map(|s| s.parse::<i32>()).collect()
+}
+
+fn collect_result_demo() {
+    println!("Collect into Result...");
+
+    let valid = collect_result(&["1", "2", "3"]);
+    let invalid = collect_result(&["1", "two", "3"]);
+
+    println!("Valid: {:?}, invalid: {:?}", valid, invalid);
+
+    assert_eq!(valid, Ok(vec![1, 2, 3]));
+    assert!(invalid.is_err());
+}
+
+fn clone_from_demo() {
+    println!("Clone from demo...");
+
+    let src = String::from("hello");
+    let mut dst = String::with_capacity(64);
+    dst.push_str("old contents");
+    let ptr_before = dst.as_ptr();
+
+    // dst = src.clone() would allocate a brand new buffer and drop the old one.
+    // clone_from copies into dst's existing buffer when it's big enough, saving an allocation.
+    // That's why it takes &mut self and &source instead of returning a new value
+    dst.clone_from(&src);
+
+    println!("dst = {dst}, capacity = {}", dst.capacity());
+
+    assert_eq!(dst, src);
+    assert!(dst.capacity() >= 64);
+    assert_eq!(dst.as_ptr(), ptr_before);
+}
+
+fn copy_within_demo() {
+    println!("Copy within demo...");
+
+    let mut v = vec![1, 2, 3, 4, 5];
+
+    // Reading &v[1..3] while writing to &mut v[0..2] would need two overlapping borrows,
+    // which the borrow checker doesn't allow. copy_within does it internally with a single &mut.
+    // It requires T: Copy as the values are copied bit by bit, nothing is moved or cloned
+    v.copy_within(1..3, 0);
+
+    println!("After copy_within: {:?}", v);
+
+    assert_eq!(v, vec![2, 3, 3, 4, 5]);
+}
+
+enum Tree {
+    Leaf(i32),
+    Node(Box<Tree>, Box<Tree>),
+}
+
+// Borrowing: walks the tree through references, the caller keeps the tree
+fn sum_tree(tree: &Tree) -> i32 {
+    match tree {
+        Tree::Leaf(value) => *value,
+        Tree::Node(left, right) => sum_tree(left) + sum_tree(right),
+    }
+}
+
+// Consuming: takes the tree by value and moves each box out as it goes.
+// Every node is dropped once its children have been taken
+fn into_leaves(tree: Tree) -> Vec<i32> {
+    match tree {
+        Tree::Leaf(value) => vec![value],
+        Tree::Node(left, right) => {
+            let mut leaves = into_leaves(*left);
+            leaves.extend(into_leaves(*right));
+            leaves
+        }
+    }
+}
+
+fn tree_demo() {
+    println!("Tree demo...");
+
+    let tree = Tree::Node(
+        Box::new(Tree::Leaf(1)),
+        Box::new(Tree::Node(Box::new(Tree::Leaf(2)), Box::new(Tree::Leaf(3)))),
+    );
+
+    let sum = sum_tree(&tree);
+    let leaves = into_leaves(tree);
+    // tree is no longer valid as into_leaves consumed it
+    // sum_tree(&tree); // This will cause an error
+    println!("Sum: {sum}, leaves: {:?}", leaves);
+
+    assert_eq!(sum, 6);
+    assert_eq!(leaves, vec![1, 2, 3]);
+}
+
+fn fmt_write_demo() -> String {
+    // Bringing the trait into scope lets write! append to a String
+    use std::fmt::Write;
+
+    // format! creates a new String every time, then push_str copies it in.
+    // write! formats straight into the buffer we already own and borrow mutably here
+    let mut s = String::with_capacity(32);
+    for i in 1..=3 {
+        write!(&mut s, "{i}:{} ", i * i).unwrap();
+    }
+    s
+}
+
+fn fmt_write_print() {
+    println!("fmt::Write demo...");
+
+    let s = fmt_write_demo();
+    println!("Built: {s}");
+
+    assert_eq!(s, "1:1 2:4 3:9 ");
+}
+
+// A version of &s[start..end] that never panics:
+// out of range bounds are clamped to s.len(), bounds inside a character snap back to its start,
+// and start > end gives an empty slice
+fn clamp_slice(s: &str, start: usize, end: usize) -> &str {
+    let end = floor_boundary(s, end);
+    let start = floor_boundary(s, start.min(end));
+    &s[start..end]
+}
+
+fn clamp_slice_demo() {
+    println!("Clamp slice...");
+
+    let s = String::from("hello world");
+    println!("Slice 6..100: {}", clamp_slice(&s, 6, 100));
+
+    assert_eq!(clamp_slice(&s, 0, 100), "hello world");
+    assert_eq!(clamp_slice(&s, 6, 100), "world");
+    assert_eq!(clamp_slice(&s, 8, 3), "");
+    assert_eq!(clamp_slice("héllo", 0, 2), "h");
+}
+
+struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    // Move in: the stack takes ownership of item
+    fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    // Move out: the caller gets ownership of the top item
+    fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    // Borrow: the caller can look at the top item, but the stack still owns it
+    fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+}
+
+fn stack_demo() {
+    println!("Stack demo...");
+
+    let mut stack = Stack::new();
+    let s = String::from("first");
+    stack.push(s);
+    // s is no longer valid as it was moved into the stack
+    // println!("{s}"); // This will cause an error
+    stack.push(String::from("second"));
+
+    // Peeking twice gives the same item as nothing is taken out
+    assert_eq!(stack.peek(), Some(&String::from("second")));
+    assert_eq!(stack.peek(), Some(&String::from("second")));
+
+    let top = stack.pop();
+    println!("Popped: {:?}, next: {:?}", top, stack.peek());
+
+    assert_eq!(top, Some(String::from("second")));
+    assert_eq!(stack.pop(), Some(String::from("first")));
+    assert_eq!(stack.pop(), None);
+}
+
+// std::str::from_utf8 only checks the bytes and reinterprets them as a &str pointing to the same memory.
+// Nothing is allocated, unlike String::from_utf8 which needs to own a Vec<u8>
+fn bytes_slice_to_str(bytes: &[u8]) -> Result<&str, std::str::Utf8Error> {
+    std::str::from_utf8(bytes)
+}
+
+fn bytes_slice_to_str_demo() {
+    println!("Bytes slice to str...");
+
+    let bytes = [104, 101, 108, 108, 111];
+    let s = bytes_slice_to_str(&bytes);
+    println!("Valid: {:?}, invalid: {:?}", s, bytes_slice_to_str(&[0xff]));
+
+    assert_eq!(s, Ok("hello"));
+    assert_eq!(s.unwrap().as_ptr(), bytes.as_ptr());
+    assert!(bytes_slice_to_str(&[0xff]).is_err());
+}
+
+fn deque_demo() {
+    println!("Deque demo...");
+
+    let mut deque = VecDeque::new();
+
+    // Both push methods move the String into the deque
+    deque.push_back(String::from("middle"));
+    deque.push_front(String::from("front"));
+    deque.push_back(String::from("back"));
+    println!("Deque: {:?}", deque);
+
+    assert_eq!(deque, ["front", "middle", "back"]);
+
+    // Both pop methods move a String back out to us
+    let front = deque.pop_front();
+    let back = deque.pop_back();
+    println!("Front: {:?}, back: {:?}, left: {:?}", front, back, deque);
+
+    assert_eq!(front, Some(String::from("front")));
+    assert_eq!(back, Some(String::from("back")));
+    assert_eq!(deque, ["middle"]);
+}
+
+fn shadow_parse_demo() -> i32 {
+    let s = "42";
+
+    // The new s shadows the old one and has a different type.
+    // The old &str can't be named anymore. It only borrowed a literal, so there is nothing to free
+    let s: i32 = s.parse().unwrap();
+    s
+}
+
+fn shadow_parse_print() {
+    println!("Shadow parse...");
+
+    let n = shadow_parse_demo();
+    println!("Parsed: {n}");
+
+    assert_eq!(n, 42);
+}
+
+fn is_allowed(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == ' ' || c == '-' || c == '_'
+}
+
+// Most inputs are already clean, so we only allocate when something has to be removed.
+// Callers get a Cow either way and can use it as a &str
+fn sanitize(input: &str) -> Cow<'_, str> {
+    if input.chars().all(is_allowed) {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(input.chars().filter(|&c| is_allowed(c)).collect())
+    }
+}
+
+fn sanitize_demo() {
+    println!("Sanitize demo...");
+
+    let clean = sanitize("hello world");
+    let dirty = sanitize("<b>hi</b>!");
+    println!("Clean: {clean}, dirty: {dirty}");
+
+    assert!(matches!(clean, Cow::Borrowed("hello world")));
+    assert!(matches!(dirty, Cow::Owned(_)));
+    assert_eq!(dirty, "bhib");
+}
+
+// Every step takes self by value and returns a new Pipeline, so the value is moved along the chain.
+// There is never more than one owner and no clones are needed
+struct Pipeline {
+    value: String,
+}
+
+impl Pipeline {
+    fn new(value: &str) -> Self {
+        Pipeline { value: value.to_string() }
+    }
+
+    // to_* methods usually borrow, this one consumes on purpose to keep the chain move-only
+    #[allow(clippy::wrong_self_convention)]
+    fn to_upper(self) -> Self {
+        Pipeline { value: self.value.to_uppercase() }
+    }
+
+    fn reverse(self) -> Self {
+        Pipeline { value: self.value.chars().rev().collect() }
+    }
+
+    fn finish(self) -> String {
+        self.value
+    }
+}
+
+fn consuming_chain_demo() {
+    println!("Consuming chain...");
+
+    let start = Pipeline::new("hello");
+    let result = start.to_upper().reverse().finish();
+    // start is no longer valid as it was moved into the chain
+    // start.finish(); // This will cause an error
+    println!("Result: {result}");
+
+    assert_eq!(result, "OLLEH");
+}
+
+fn index_then_push() {
+    println!("Index then push...");
+
+    // i32 is Copy, so reading v[0] copies the value out and no borrow is kept
+    let mut v = vec![1, 2, 3];
+    let first = v[0];
+    v.push(first);
+
+    // With non-Copy values we can't do the same thing:
+    // words.push(words[0]); // This will cause an error as we can't move out of an index
+    // Keeping a reference instead doesn't work either, as push may reallocate and leave it dangling:
+    // let first_word = &words[0];
+    // words.push(String::from("x")); // This will cause an error as words is borrowed
+    // println!("{first_word}");
+    // So we clone first, and the clone is an independent owned value
+    let mut words = vec![String::from("a"), String::from("b")];
+    let first_word = words[0].clone();
+    words.push(first_word);
+
+    println!("v: {:?}, words: {:?}", v, words);
+
+    assert_eq!(v, vec![1, 2, 3, 1]);
+    assert_eq!(words, vec!["a", "b", "a"]);
+}
+
+// str is unsized: its length is only known at runtime, so it can't live directly in a variable
+// or be passed by value. It always sits behind a pointer like &str or Box<str>, which stores { ptr, len }.
+// String is sized: it is always { ptr, len, capacity } on the stack and owns the text on the heap
+fn takes_str(s: &str) -> usize {
+    s.len()
+}
+
+// This won't compile as the size of str isn't known at compile time
+// fn takes_str_by_value(s: str) -> usize {
+//     s.len()
+// }
+
+fn sized_demo() {
+    println!("Sized demo...");
+
+    let owned = String::from("hello");
+    let boxed: Box<str> = Box::from("hi");
+
+    let len1 = takes_str(&owned);
+    let len2 = takes_str(&boxed);
+    let len3 = takes_str("literal");
+    println!("Lengths: {len1} {len2} {len3}");
+
+    // &str is two words (ptr, len), String is three (ptr, len, capacity)
+    assert_eq!(std::mem::size_of::<&str>(), 2 * std::mem::size_of::<usize>());
+    assert_eq!(std::mem::size_of::<String>(), 3 * std::mem::size_of::<usize>());
+    assert_eq!((len1, len2, len3), (5, 2, 7));
+}
+
+// matches! expands to a match on opt. As opt is a reference, s binds as &String and nothing is moved
+fn matches_demo(opt: &Option<String>) -> bool {
+    matches!(opt, Some(s) if s.len() > 2)
+}
+
+fn matches_macro_demo() {
+    println!("matches! demo...");
+
+    let long = Some(String::from("hello"));
+    let short = Some(String::from("hi"));
+    let none = None;
+
+    println!("long: {}, short: {}, none: {}", matches_demo(&long), matches_demo(&short), matches_demo(&none));
+
+    assert!(matches_demo(&long));
+    assert!(!matches_demo(&short));
+    assert!(!matches_demo(&none));
+    // long is still usable afterward
+    assert_eq!(long.as_deref(), Some("hello"));
+}
+
+fn into_values_demo() {
+    println!("Into values demo...");
+
+    let mut capitals = HashMap::new();
+    capitals.insert(String::from("France"), String::from("Paris"));
+    capitals.insert(String::from("Japan"), String::from("Tokyo"));
+
+    // values() only borrows, each item is a &String and the map stays intact
+    let borrowed: Vec<&String> = capitals.values().collect();
+    println!("Borrowed values: {}", borrowed.len());
+
+    // into_values() consumes the map: keys are dropped and every value is moved out to us
+    let mut owned: Vec<String> = capitals.into_values().collect();
+    // println!("{:?}", capitals); // This will cause an error as capitals is moved
+
+    // HashMap has no order, so sort before comparing
+    owned.sort();
+    println!("Owned values: {:?}", owned);
+
+    assert_eq!(owned, vec!["Paris", "Tokyo"]);
+}
+
+fn builder_parts_demo() {
+    println!("Builder parts...");
+
+    let mut builder = SentenceBuilder::new();
+    builder.add_owned(String::from("borrow")).add_owned(String::from("first"));
+
+    let parts = builder.parts();
+    println!("Parts so far: {:?}", parts);
+    assert_eq!(parts, ["borrow", "first"]);
+
+    let sentence = builder.build();
+    // builder is moved, so the borrowed parts can't be used anymore
+    // println!("{:?}", parts); // This will cause an error
+    assert_eq!(sentence, "borrow first");
+}
+
+// Same idea as as_ref_demo, but for paths: &str, String, PathBuf and &Path can all be borrowed as &Path
+fn describe_path<P: AsRef<Path>>(p: P) -> String {
+    match p.as_ref().extension() {
+        Some(ext) => ext.to_string_lossy().into_owned(),
+        None => String::from("none"),
+    }
+}
+
+fn describe_path_demo() {
+    println!("Describe path...");
+
+    let buf = PathBuf::from("src/main.rs");
+    let from_str = describe_path("notes.txt");
+    // Passing &buf only borrows it, so buf is still usable afterward
+    let from_buf = describe_path(&buf);
+    let no_ext = describe_path(Path::new("Cargo"));
+    println!("Extensions: {from_str} {from_buf} {no_ext}, path still valid: {}", buf.display());
+
+    assert_eq!(from_str, "txt");
+    assert_eq!(from_buf, "rs");
+    assert_eq!(no_ext, "none");
+    assert_eq!(describe_path(buf), "rs");
+}
+
+// once(prefix) is an iterator that owns prefix and yields it a single time.
+// chain(rest) consumes rest, so every String ends up moved into the result
+fn iter_compose(prefix: String, rest: Vec<String>) -> Vec<String> {
+    std::iter::once(prefix).chain(rest).collect()
+}
+
+fn iter_compose_demo() {
+    println!("Iterator compose...");
+
+    let result = iter_compose(String::from("first"), vec![String::from("second"), String::from("third")]);
+    println!("Composed: {:?}", result);
+
+    assert_eq!(result, vec!["first", "second", "third"]);
+}
+
+fn transform(s: &str) -> String {
+    format!("<{s}>")
+}
+
+// The index loop is kept on purpose to compare it with iter_mut()
+#[allow(clippy::needless_range_loop)]
+fn in_place_map() {
+    println!("In place map...");
+
+    let mut v = vec![String::from("a"), String::from("b")];
+
+    // Each iteration borrows v[i] only for the call to transform, then assigns the new String.
+    // No borrow is held across iterations, so reading and writing the same element is fine
+    for i in 0..v.len() {
+        v[i] = transform(&v[i]);
+    }
+
+    // iter_mut() hands out one &mut String per element for the whole loop body instead
+    let mut w = vec![String::from("a"), String::from("b")];
+    for s in w.iter_mut() {
+        *s = transform(s);
+    }
+
+    println!("Index: {:?}, iter_mut: {:?}", v, w);
+
+    assert_eq!(v, vec!["<a>", "<b>"]);
+    assert_eq!(v, w);
+}
+
+struct Wrapper<T: std::fmt::Display> {
+    inner: T,
+}
+
+impl<T: std::fmt::Display> Wrapper<T> {
+    // inner is moved into the wrapper (or copied, for Copy types like i32)
+    fn new(inner: T) -> Self {
+        Wrapper { inner }
+    }
+
+    // Only borrows self, so we can call it as many times as we like
+    fn show(&self) -> String {
+        format!("[{}]", self.inner)
+    }
+}
+
+fn wrapper_demo() {
+    println!("Wrapper demo...");
+
+    let text = String::from("hello");
+    let wrapped_text = Wrapper::new(text);
+    // text is no longer valid as it was moved into the wrapper
+    // println!("{text}"); // This will cause an error
+
+    let number = 42;
+    let wrapped_number = Wrapper::new(number);
+    // number is still valid as i32 was copied
+    println!("{} {} {number}", wrapped_text.show(), wrapped_number.show());
+
+    assert_eq!(wrapped_text.show(), "[hello]");
+    assert_eq!(wrapped_number.show(), "[42]");
+}
+
+// derive(Clone) calls clone() on every field, so both the String and the Vec are deep copied
+#[derive(Clone, Debug)]
+struct Profile {
+    name: String,
+    tags: Vec<String>,
+}
+
+fn derived_clone_demo() {
+    println!("Derived clone...");
+
+    let original = Profile {
+        name: String::from("alice"),
+        tags: vec![String::from("admin")],
+    };
+
+    // The clone gets its own heap allocations, changing it doesn't affect the original
+    let mut copy = original.clone();
+    copy.name.push_str("_copy");
+    copy.tags.push(String::from("guest"));
+
+    println!("Original: {:?}, copy: {:?}", original, copy);
+
+    assert_eq!(original.name, "alice");
+    assert_eq!(original.tags, vec!["admin"]);
+    assert_eq!(copy.name, "alice_copy");
+    assert_eq!(copy.tags, vec!["admin", "guest"]);
+}
+
+struct Cache {
+    entries: Vec<(String, u64)>,
+}
+
+impl Cache {
+    fn new() -> Self {
+        Cache { entries: Vec::new() }
+    }
+
+    // The cache takes ownership of the key
+    fn insert(&mut self, key: String, ts: u64) {
+        self.entries.push((key, ts));
+    }
+
+    // retain keeps the entries that return true. The others are dropped right away,
+    // which frees each evicted key's String
+    fn evict_older_than(&mut self, cutoff: u64) {
+        self.entries.retain(|(_, ts)| *ts >= cutoff);
+    }
+}
+
+fn cache_demo() {
+    println!("Cache demo...");
+
+    let mut cache = Cache::new();
+    cache.insert(String::from("old"), 10);
+    cache.insert(String::from("fresh"), 50);
+    cache.insert(String::from("edge"), 30);
+
+    cache.evict_older_than(30);
+    let keys: Vec<&str> = cache.entries.iter().map(|(k, _)| k.as_str()).collect();
+    println!("Remaining keys: {:?}", keys);
+
+    assert_eq!(keys, vec!["fresh", "edge"]);
+}
+
+// Like chunks() on a slice, but for &str: each chunk is at most size bytes
+// and is cut back to a char boundary so no character is ever split.
+// If a single character is bigger than size, it gets a chunk of its own
+fn byte_chunks(s: &str, size: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    while start < s.len() {
+        let mut end = floor_boundary(s, start + size);
+        if end == start {
+            // The next character doesn't fit, so move forward to its end instead
+            end = start + 1;
+            while !s.is_char_boundary(end) {
+                end += 1;
+            }
+        }
+        chunks.push(&s[start..end]);
+        start = end;
+    }
+
+    chunks
+}
+
+fn byte_chunks_demo() {
+    println!("Byte chunks...");
+
+    let ascii = byte_chunks("abcdef", 2);
+    let multi_byte = byte_chunks("héllo", 2);
+    println!("ASCII: {:?}, multi-byte: {:?}", ascii, multi_byte);
+
+    assert_eq!(ascii, vec!["ab", "cd", "ef"]);
+    // "é" is 2 bytes, so it can't share a chunk with "h"
+    assert_eq!(multi_byte, vec!["h", "é", "ll", "o"]);
+    assert_eq!(byte_chunks("éé", 1), vec!["é", "é"]);
+}
+
+// A hand-written version of items.into_iter().map(f).collect().
+// Each T is moved out of items and into f, and f gives back a new owned U
+fn map_owned<T, U>(items: Vec<T>, f: impl Fn(T) -> U) -> Vec<U> {
+    let mut result = Vec::with_capacity(items.len());
+    for item in items {
+        result.push(f(item));
+    }
+    result
+}
+
+fn map_owned_demo() {
+    println!("Map owned...");
+
+    let words = vec![String::from("one"), String::from("three")];
+    // Each String is dropped inside the closure once its length is taken
+    let lengths = map_owned(words, |s| s.len());
+    // println!("{:?}", words); // This will cause an error as words is moved
+    println!("Lengths: {:?}", lengths);
+
+    assert_eq!(lengths, vec![3, 5]);
+}
+
+fn registry_summary_demo() {
+    println!("Registry summary...");
+
+    let summary;
+    {
+        let registry = Registry {
+            names: vec![String::from("alice"), String::from("bob")],
+        };
+        summary = registry.summary();
+    } // registry is dropped here, but summary owns its own data
+
+    println!("{summary}");
+
+    assert_eq!(summary, "2 names: alice, bob");
+}
+
+// repeat() takes ownership of word and hands out a clone of it on every next().
+// So n items cost n String allocations. That's fine for small values, but expensive for big ones.
+// std::iter::repeat_n(word, n) saves one clone by moving word itself out as the last item
+#[allow(clippy::manual_repeat_n)]
+fn repeat_demo(word: String, n: usize) -> Vec<String> {
+    std::iter::repeat(word).take(n).collect()
+}
+
+fn repeat_demo_print() {
+    println!("Repeat demo...");
+
+    let repeated = repeat_demo(String::from("hi"), 3);
+    println!("Repeated: {:?}", repeated);
+
+    assert_eq!(repeated.len(), 3);
+    assert!(repeated.iter().all(|s| s == "hi"));
+    // Every item is its own allocation
+    assert_ne!(repeated[0].as_ptr(), repeated[1].as_ptr());
+}
+
+fn by_ref_demo() {
+    println!("by_ref demo...");
+
+    let v = vec![
+        String::from("a"),
+        String::from("b"),
+        String::from("c"),
+        String::from("d"),
+    ];
+    let mut iter = v.into_iter();
+
+    // take() would normally consume the iterator itself.
+    // by_ref() lends it out as &mut, so take(2) only moves two Strings out and we keep the iterator
+    let first_two: Vec<String> = iter.by_ref().take(2).collect();
+
+    // The iterator resumes where it stopped and moves out the rest
+    let rest: Vec<String> = iter.collect();
+
+    println!("First two: {:?}, rest: {:?}", first_two, rest);
+
+    assert_eq!(first_two, vec!["a", "b"]);
+    assert_eq!(rest, vec!["c", "d"]);
+}
+
+// A pattern in the parameter list works exactly like let (a, b) = tuple;
+// a takes ownership of the String and b gets a copy of the i32
+fn destructure_param((a, b): (String, i32)) -> String {
+    format!("{a}-{b}")
+}
+
+fn destructure_param_demo() {
+    println!("Destructure param...");
+
+    let pair = (String::from("item"), 7);
+    let combined = destructure_param(pair);
+    // println!("{:?}", pair); // This will cause an error as pair.0 was moved
+    println!("Combined: {combined}");
+
+    assert_eq!(combined, "item-7");
+}
+
+// The returned reference comes from words, so it stays valid in the caller
+// for as long as words does. Returning early from the loop is fine.
+// words.iter().find(...) does the same thing, the loop is kept to show the early return
+#[allow(clippy::manual_find)]
+fn find_first_long(words: &[String]) -> Option<&String> {
+    for word in words {
+        if word.len() > 4 {
+            return Some(word);
+        }
+    }
+    None
+}
+
+fn find_first_long_demo() {
+    println!("Find first long...");
+
+    let words = vec![String::from("hi"), String::from("hello"), String::from("greetings")];
+    let found = find_first_long(&words);
+    let short = [String::from("a"), String::from("bc")];
+    println!("Found: {:?}, in short list: {:?}", found, find_first_long(&short));
+
+    assert_eq!(found, Some(&words[1]));
+    assert_eq!(find_first_long(&short), None);
+}
+
+// std::cmp::max takes both arguments by value: the larger one is returned, the other is dropped
+fn max_owned(a: String, b: String) -> String {
+    std::cmp::max(a, b)
+}
+
+fn max_owned_demo() {
+    println!("Max owned...");
+
+    let a = String::from("apple");
+    let b = String::from("banana");
+    let larger = max_owned(a, b);
+    // a and b are no longer valid as both were moved into max_owned
+    // println!("{a}"); // This will cause an error
+
+    // Comparing borrows keeps both Strings and just gives back a reference to the larger one
+    let c = String::from("cherry");
+    let d = String::from("date");
+    let larger_ref = if c >= d { &c } else { &d };
+    println!("Larger owned: {larger}, larger borrowed: {larger_ref}");
+
+    assert_eq!(larger, "banana");
+    assert_eq!(max_owned(String::from("b"), String::from("a")), "b");
+    assert_eq!(larger_ref, "date");
+}
+
+// A closure has no name for its type, so we store it as a boxed trait object.
+// The struct owns the Box, and the Box owns the closure together with anything it captured
+struct Callback {
+    f: Box<dyn Fn(&str) -> usize>,
+}
+
+impl Callback {
+    fn new(f: Box<dyn Fn(&str) -> usize>) -> Self {
+        Callback { f }
+    }
+
+    // Borrowing self is enough to call a Fn closure
+    fn run(&self, s: &str) -> usize {
+        (self.f)(s)
+    }
+
+    // Gives ownership of the closure back to the caller and consumes the Callback
+    fn into_inner(self) -> Box<dyn Fn(&str) -> usize> {
+        self.f
+    }
+}
+
+fn callback_demo() {
+    println!("Callback demo...");
+
+    let callback = Callback::new(Box::new(|s| first_word(s).len()));
+    let len = callback.run("hello world");
+    println!("First word length: {len}");
+
+    let f = callback.into_inner();
+    // callback is no longer valid as into_inner consumed it
+    // callback.run("again"); // This will cause an error
+
+    assert_eq!(len, 5);
+    assert_eq!(f("hi there"), 2);
+}
+
+// flatten() consumes the outer Option and moves the inner one out, the String is never copied
+fn flatten_option(x: Option<Option<String>>) -> Option<String> {
+    x.flatten()
+}
+
+fn flatten_option_demo() {
+    println!("Flatten option...");
+
+    let nested = Some(Some(String::from("hello")));
+    let flat = flatten_option(nested);
+    println!("Flattened: {:?}", flat);
+
+    assert_eq!(flat, Some(String::from("hello")));
+    assert_eq!(flatten_option(Some(None)), None);
+    assert_eq!(flatten_option(None), None);
+}
+
 fn main() {
     mutable_string();
     multiple_variables_simple();
@@ -273,4 +2903,104 @@ fn main() {
     using_slice_function();
     general_string_slice();
     other_slices();
+    tuple_partial_move();
+    returning_borrowed_data();
+    shared_mutable_state();
+    shared_mutable_threads();
+    from_into_demo();
+    chunks_and_windows();
+    map_remove_ownership();
+    higher_order_demo();
+    flatten_ownership();
+    trim_demos();
+    result_ownership();
+    iterator_pipeline();
+    as_ref_and_as_mut();
+    ref_vec_demo();
+    to_owned_slices();
+    linked_list_build();
+    parser_demo();
+    default_demo();
+    slice_pattern_demo();
+    bytes_to_string_demo();
+    scoped_threads();
+    array_vs_vec_copy();
+    scoped_borrows();
+    reborrow_str_demo();
+    fold_demo();
+    borrow_trait_lookup();
+    safe_byte_slice_demo();
+    owned_word_iter_demo();
+    cell_demo();
+    recursive_closure();
+    take_first_owned_demo();
+    custom_hash_key();
+    registry_demo();
+    once_cell_demo();
+    box_str_demo();
+    split_off_demo();
+    custom_deref();
+    match_guard_ownership();
+    sort_ownership();
+    dedup_demo();
+    task_demo();
+    maybe_owned_demo();
+    into_iterator_refs();
+    retain_mut_demo();
+    generic_swap_demo();
+    byte_vs_char_demo();
+    pool_demo();
+    rc_get_mut();
+    array_sum_demo();
+    once_lock_demo();
+    vec_of_structs_borrow();
+    extend_demo();
+    run_of_demo();
+    option_map_ownership();
+    mutate_str_slice();
+    try_from_demo();
+    reverse_consume_demo();
+    drop_on_early_return_demo();
+    split_demo();
+    sentence_builder_demo();
+    compare_demo();
+    fnonce_demo();
+    nested_borrow_demo();
+    forget_demo();
+    split_any_demo();
+    binary_heap_demo();
+    map_iteration();
+    collect_result_demo();
+    clone_from_demo();
+    copy_within_demo();
+    tree_demo();
+    fmt_write_print();
+    clamp_slice_demo();
+    stack_demo();
+    bytes_slice_to_str_demo();
+    deque_demo();
+    shadow_parse_print();
+    sanitize_demo();
+    consuming_chain_demo();
+    index_then_push();
+    sized_demo();
+    matches_macro_demo();
+    into_values_demo();
+    builder_parts_demo();
+    describe_path_demo();
+    iter_compose_demo();
+    in_place_map();
+    wrapper_demo();
+    derived_clone_demo();
+    cache_demo();
+    byte_chunks_demo();
+    map_owned_demo();
+    registry_summary_demo();
+    repeat_demo_print();
+    by_ref_demo();
+    destructure_param_demo();
+    find_first_long_demo();
+    max_owned_demo();
+    callback_demo();
+    flatten_option_demo();
 }